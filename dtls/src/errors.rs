@@ -0,0 +1,53 @@
+use lazy_static::lazy_static;
+
+use util::Error;
+
+// This chunk's checkout doesn't include the rest of this crate's errors
+// module, so `ERR_INVALID_SNI_FORMAT` and `ERR_INVALID_EXTENSION_TYPE`
+// (already referenced by `extension.rs`/`extension_server_name.rs` before
+// this series) are assumed to live there already. Only the constants this
+// series introduces are added here.
+lazy_static! {
+    /// A cryptobyte `Builder` child region grew past what its reserved
+    /// length-prefix width (u8/u16/u24) can express.
+    pub static ref ERR_LENGTH_OVERFLOW: Error =
+        Error::new("cryptobyte: child length overflows its length-prefix width".to_owned());
+
+    /// A cryptobyte `Reader` was asked to read more bytes than remain in
+    /// its bounded region.
+    pub static ref ERR_BUFFER_TOO_SMALL: Error =
+        Error::new("cryptobyte: buffer too small for requested read".to_owned());
+
+    /// An ALPN `ProtocolNameList` was empty or had an entry with a
+    /// zero-length protocol name.
+    pub static ref ERR_INVALID_ALPN_FORMAT: Error =
+        Error::new("alpn: invalid protocol name list format".to_owned());
+
+    /// A `SupportedVersions` list/selected-version was empty or missing
+    /// where the wire format requires one.
+    pub static ref ERR_INVALID_SUPPORTED_VERSIONS_FORMAT: Error =
+        Error::new("supported_versions: invalid format".to_owned());
+
+    /// A fixed-width list's declared length wasn't a multiple of its
+    /// element width, so it can't be exactly divided into elements.
+    pub static ref ERR_INVALID_LIST_FORMAT: Error =
+        Error::new("cryptobyte: fixed-width list length doesn't match its element width".to_owned());
+
+    /// A fixed-width list declared more elements than the caller's cap,
+    /// so it was rejected before allocating space for them.
+    pub static ref ERR_TOO_MANY_ELEMENTS: Error =
+        Error::new("cryptobyte: fixed-width list exceeds the maximum element count".to_owned());
+
+    /// An extension's body had bytes left over after its concrete type's
+    /// `unmarshal` consumed what it needed from `extension_data`.
+    pub static ref ERR_INVALID_EXTENSION_FORMAT: Error =
+        Error::new("extension: trailing bytes after extension_data".to_owned());
+
+    /// A `NamedCurveList` was empty.
+    pub static ref ERR_INVALID_ELLIPTIC_CURVE_FORMAT: Error =
+        Error::new("supported_elliptic_curves: invalid named curve list format".to_owned());
+
+    /// A `supported_signature_algorithms` list was empty.
+    pub static ref ERR_INVALID_SIGNATURE_ALGORITHMS_FORMAT: Error =
+        Error::new("supported_signature_algorithms: invalid format".to_owned());
+}
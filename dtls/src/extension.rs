@@ -1,14 +1,21 @@
+mod cryptobyte;
+
+pub mod extension_alpn;
 pub mod extension_server_name;
 pub mod extension_supported_elliptic_curves;
 pub mod extension_supported_point_formats;
 pub mod extension_supported_signature_algorithms;
+pub mod extension_supported_versions;
 pub mod extension_use_extended_master_secret;
 pub mod extension_use_srtp;
 
+use cryptobyte::*;
+use extension_alpn::*;
 use extension_server_name::*;
 use extension_supported_elliptic_curves::*;
 use extension_supported_point_formats::*;
 use extension_supported_signature_algorithms::*;
+use extension_supported_versions::*;
 use extension_use_extended_master_secret::*;
 use extension_use_srtp::*;
 
@@ -28,7 +35,9 @@ pub enum ExtensionValue {
     SupportedPointFormats = 11,
     SupportedSignatureAlgorithms = 13,
     UseSRTP = 14,
+    Alpn = 16,
     UseExtendedMasterSecret = 23,
+    SupportedVersions = 43,
     Unsupported,
 }
 
@@ -40,7 +49,9 @@ impl From<u16> for ExtensionValue {
             11 => ExtensionValue::SupportedPointFormats,
             13 => ExtensionValue::SupportedSignatureAlgorithms,
             14 => ExtensionValue::UseSRTP,
+            16 => ExtensionValue::Alpn,
             23 => ExtensionValue::UseExtendedMasterSecret,
+            43 => ExtensionValue::SupportedVersions,
             _ => ExtensionValue::Unsupported,
         }
     }
@@ -53,7 +64,12 @@ pub enum Extension {
     SupportedPointFormats(ExtensionSupportedPointFormats),
     SupportedSignatureAlgorithms(ExtensionSupportedSignatureAlgorithms),
     UseSRTP(ExtensionUseSRTP),
+    Alpn(ExtensionAlpn),
     UseExtendedMasterSecret(ExtensionUseExtendedMasterSecret),
+    SupportedVersions(ExtensionSupportedVersions),
+    /// An extension type this crate doesn't model, carried verbatim so
+    /// it round-trips instead of aborting the whole handshake.
+    Unknown { extension_type: u16, data: Vec<u8> },
 }
 
 impl Extension {
@@ -64,44 +80,92 @@ impl Extension {
             Extension::SupportedPointFormats(ext) => ext.extension_value(),
             Extension::SupportedSignatureAlgorithms(ext) => ext.extension_value(),
             Extension::UseSRTP(ext) => ext.extension_value(),
+            Extension::Alpn(ext) => ext.extension_value(),
             Extension::UseExtendedMasterSecret(ext) => ext.extension_value(),
+            Extension::SupportedVersions(ext) => ext.extension_value(),
+            Extension::Unknown { .. } => ExtensionValue::Unsupported,
         }
     }
 
-    pub fn marshal<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
-        writer.write_u16::<BigEndian>(self.extension_value() as u16)?;
+    fn extension_type(&self) -> u16 {
         match self {
-            Extension::ServerName(ext) => ext.marshal(writer),
-            Extension::SupportedEllipticCurves(ext) => ext.marshal(writer),
-            Extension::SupportedPointFormats(ext) => ext.marshal(writer),
-            Extension::SupportedSignatureAlgorithms(ext) => ext.marshal(writer),
-            Extension::UseSRTP(ext) => ext.marshal(writer),
-            Extension::UseExtendedMasterSecret(ext) => ext.marshal(writer),
+            Extension::Unknown { extension_type, .. } => *extension_type,
+            _ => self.extension_value() as u16,
         }
     }
 
+    pub fn marshal<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_u16::<BigEndian>(self.extension_type())?;
+
+        let mut builder = Builder::new();
+        builder.write_length_prefixed(LengthPrefix::U16, |b| match self {
+            Extension::ServerName(ext) => ext.marshal(b),
+            Extension::SupportedEllipticCurves(ext) => ext.marshal(b),
+            Extension::SupportedPointFormats(ext) => ext.marshal(b),
+            Extension::SupportedSignatureAlgorithms(ext) => ext.marshal(b),
+            Extension::UseSRTP(ext) => ext.marshal(b),
+            Extension::Alpn(ext) => ext.marshal(b),
+            Extension::UseExtendedMasterSecret(ext) => ext.marshal(b),
+            Extension::SupportedVersions(ext) => ext.marshal(b),
+            Extension::Unknown { data, .. } => {
+                b.write_bytes(data);
+                Ok(())
+            }
+        })?;
+        writer.write_all(&builder.finish())?;
+
+        Ok(())
+    }
+
     pub fn unmarshal<R: Read>(reader: &mut R) -> Result<Self, Error> {
-        let extension_value: ExtensionValue = reader.read_u16::<BigEndian>()?.into();
-        match extension_value {
-            ExtensionValue::ServerName => Ok(Extension::ServerName(
-                ExtensionServerName::unmarshal(reader)?,
-            )),
-            ExtensionValue::SupportedEllipticCurves => Ok(Extension::SupportedEllipticCurves(
-                ExtensionSupportedEllipticCurves::unmarshal(reader)?,
-            )),
-            ExtensionValue::SupportedPointFormats => Ok(Extension::SupportedPointFormats(
-                ExtensionSupportedPointFormats::unmarshal(reader)?,
-            )),
+        let raw_extension_type = reader.read_u16::<BigEndian>()?;
+        let extension_value: ExtensionValue = raw_extension_type.into();
+
+        let data_len = reader.read_u16::<BigEndian>()? as usize;
+        let mut data = vec![0u8; data_len];
+        reader.read_exact(&mut data)?;
+        let mut r = Reader::new(&data);
+
+        let extension = match extension_value {
+            ExtensionValue::ServerName => {
+                Extension::ServerName(ExtensionServerName::unmarshal(&mut r)?)
+            }
+            ExtensionValue::SupportedEllipticCurves => Extension::SupportedEllipticCurves(
+                ExtensionSupportedEllipticCurves::unmarshal(&mut r)?,
+            ),
+            ExtensionValue::SupportedPointFormats => Extension::SupportedPointFormats(
+                ExtensionSupportedPointFormats::unmarshal(&mut r)?,
+            ),
             ExtensionValue::SupportedSignatureAlgorithms => {
-                Ok(Extension::SupportedSignatureAlgorithms(
-                    ExtensionSupportedSignatureAlgorithms::unmarshal(reader)?,
-                ))
+                Extension::SupportedSignatureAlgorithms(
+                    ExtensionSupportedSignatureAlgorithms::unmarshal(&mut r)?,
+                )
             }
-            ExtensionValue::UseSRTP => Ok(Extension::UseSRTP(ExtensionUseSRTP::unmarshal(reader)?)),
-            ExtensionValue::UseExtendedMasterSecret => Ok(Extension::UseExtendedMasterSecret(
-                ExtensionUseExtendedMasterSecret::unmarshal(reader)?,
-            )),
-            _ => Err(ERR_INVALID_EXTENSION_TYPE.clone()),
+            ExtensionValue::UseSRTP => Extension::UseSRTP(ExtensionUseSRTP::unmarshal(&mut r)?),
+            ExtensionValue::Alpn => Extension::Alpn(ExtensionAlpn::unmarshal(&mut r)?),
+            ExtensionValue::UseExtendedMasterSecret => Extension::UseExtendedMasterSecret(
+                ExtensionUseExtendedMasterSecret::unmarshal(&mut r)?,
+            ),
+            // This shared dispatch only has the wire bytes to go on, so it
+            // parses the ClientHello (list) form; a ServerHello/HRR caller
+            // that knows its own context should call
+            // `ExtensionSupportedVersions::unmarshal_server_hello` directly.
+            ExtensionValue::SupportedVersions => {
+                Extension::SupportedVersions(ExtensionSupportedVersions::unmarshal(&mut r)?)
+            }
+            ExtensionValue::Unsupported => Extension::Unknown {
+                extension_type: raw_extension_type,
+                data: r.read_to_end().to_vec(),
+            },
+        };
+
+        // `extension_data_length` must frame the body exactly; a concrete
+        // extension that stopped short of it left trailing garbage rather
+        // than a second valid structure.
+        if !r.is_empty() {
+            return Err(ERR_INVALID_EXTENSION_FORMAT.clone());
         }
+
+        Ok(extension)
     }
 }
@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod cryptobyte_test;
+
+// A small helper for writing and reading the nested, length-prefixed
+// structures that TLS/DTLS extensions are built from, so callers don't
+// have to hand-roll backpatching the way the rest of this module used to.
+// Mirrors BoringSSL's `CBB`/`CBS` and Go's `cryptobyte` package, scaled
+// down to what this crate needs.
+
+use crate::errors::*;
+
+use util::Error;
+
+/// Width, in bytes, of a length-prefix field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum LengthPrefix {
+    U8,
+    U16,
+    U24,
+}
+
+impl LengthPrefix {
+    fn width(self) -> usize {
+        match self {
+            LengthPrefix::U8 => 1,
+            LengthPrefix::U16 => 2,
+            LengthPrefix::U24 => 3,
+        }
+    }
+
+    fn max_value(self) -> usize {
+        match self {
+            LengthPrefix::U8 => u8::MAX as usize,
+            LengthPrefix::U16 => u16::MAX as usize,
+            LengthPrefix::U24 => 0x00ff_ffff,
+        }
+    }
+}
+
+/// A growable byte buffer that can open a length-prefixed child region and
+/// backfill its length once the child has finished writing.
+#[derive(Default)]
+pub(crate) struct Builder {
+    buf: Vec<u8>,
+}
+
+impl Builder {
+    pub(crate) fn new() -> Self {
+        Builder::default()
+    }
+
+    pub(crate) fn write_u8(&mut self, v: u8) {
+        self.buf.push(v);
+    }
+
+    pub(crate) fn write_bytes(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    /// Reserve space for a `prefix`-wide length, run `f` to append the
+    /// child's contents, then backfill the reservation with the measured
+    /// length of whatever `f` wrote.
+    pub(crate) fn write_length_prefixed<F>(
+        &mut self,
+        prefix: LengthPrefix,
+        f: F,
+    ) -> Result<(), Error>
+    where
+        F: FnOnce(&mut Builder) -> Result<(), Error>,
+    {
+        let width = prefix.width();
+        let start = self.buf.len();
+        self.buf.resize(start + width, 0);
+
+        f(self)?;
+
+        let len = self.buf.len() - start - width;
+        if len > prefix.max_value() {
+            return Err(ERR_LENGTH_OVERFLOW.clone());
+        }
+
+        let len_bytes = (len as u32).to_be_bytes();
+        self.buf[start..start + width].copy_from_slice(&len_bytes[4 - width..]);
+
+        Ok(())
+    }
+
+    pub(crate) fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// A reader bounded to a fixed byte region; reads cannot cross that bound.
+pub(crate) struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Reader { buf, pos: 0 }
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8, Error> {
+        let b = self.read_bytes(1)?;
+        Ok(b[0])
+    }
+
+    pub(crate) fn read_u16(&mut self) -> Result<u16, Error> {
+        let b = self.read_bytes(2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    pub(crate) fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if self.remaining() < n {
+            return Err(ERR_BUFFER_TOO_SMALL.clone());
+        }
+        let out = &self.buf[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(out)
+    }
+
+    /// Consume and return every byte left in this reader's bound.
+    pub(crate) fn read_to_end(&mut self) -> &'a [u8] {
+        let out = &self.buf[self.pos..];
+        self.pos = self.buf.len();
+        out
+    }
+
+    /// Read a `prefix`-wide length and return a sub-reader scoped to
+    /// exactly that many following bytes.
+    pub(crate) fn read_length_prefixed(
+        &mut self,
+        prefix: LengthPrefix,
+    ) -> Result<Reader<'a>, Error> {
+        let len = match prefix {
+            LengthPrefix::U8 => self.read_u8()? as usize,
+            LengthPrefix::U16 => self.read_u16()? as usize,
+            LengthPrefix::U24 => {
+                let hi = self.read_u8()? as usize;
+                let mid = self.read_u8()? as usize;
+                let lo = self.read_u8()? as usize;
+                (hi << 16) | (mid << 8) | lo
+            }
+        };
+        let data = self.read_bytes(len)?;
+        Ok(Reader::new(data))
+    }
+
+    /// Read a `prefix`-wide length and decode it as a list of fixed-width
+    /// (`element_width` bytes each) elements, e.g. the `NamedGroup<2>` and
+    /// `SignatureScheme<2>` lists in `SupportedEllipticCurves` and
+    /// `SupportedSignatureAlgorithms`. Enforces that the declared length
+    /// frames the list exactly - any leftover bytes are a malformed list,
+    /// not silently ignored - and rejects the list before allocating if
+    /// its element count exceeds `max_elements`, so a hostile peer can't
+    /// force a huge allocation with a single crafted length.
+    pub(crate) fn read_fixed_width_list<T>(
+        &mut self,
+        prefix: LengthPrefix,
+        element_width: usize,
+        max_elements: usize,
+        mut decode_element: impl FnMut(&mut Reader) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let mut list = self.read_length_prefixed(prefix)?;
+
+        if list.remaining() % element_width != 0 {
+            return Err(ERR_INVALID_LIST_FORMAT.clone());
+        }
+
+        let count = list.remaining() / element_width;
+        if count > max_elements {
+            return Err(ERR_TOO_MANY_ELEMENTS.clone());
+        }
+
+        let mut elements = Vec::with_capacity(count);
+        while !list.is_empty() {
+            elements.push(decode_element(&mut list)?);
+        }
+
+        Ok(elements)
+    }
+}
@@ -0,0 +1,128 @@
+use super::*;
+
+#[test]
+fn test_write_length_prefixed_round_trip_u8() {
+    let mut b = Builder::new();
+    b.write_length_prefixed(LengthPrefix::U8, |c| {
+        c.write_bytes(b"hi");
+        Ok(())
+    })
+    .unwrap();
+
+    let buf = b.finish();
+    assert_eq!(buf, vec![2, b'h', b'i']);
+
+    let mut r = Reader::new(&buf);
+    let mut child = r.read_length_prefixed(LengthPrefix::U8).unwrap();
+    assert_eq!(child.read_to_end(), b"hi");
+    assert!(r.is_empty());
+}
+
+#[test]
+fn test_write_length_prefixed_round_trip_u16() {
+    let data = vec![0xab; 300];
+
+    let mut b = Builder::new();
+    b.write_length_prefixed(LengthPrefix::U16, |c| {
+        c.write_bytes(&data);
+        Ok(())
+    })
+    .unwrap();
+
+    let buf = b.finish();
+    assert_eq!(&buf[..2], &[0x01, 0x2c]); // 300 as u16 big-endian
+
+    let mut r = Reader::new(&buf);
+    let mut child = r.read_length_prefixed(LengthPrefix::U16).unwrap();
+    assert_eq!(child.read_to_end(), data.as_slice());
+    assert!(r.is_empty());
+}
+
+#[test]
+fn test_write_length_prefixed_round_trip_u24() {
+    let data = vec![0x42; 70_000];
+
+    let mut b = Builder::new();
+    b.write_length_prefixed(LengthPrefix::U24, |c| {
+        c.write_bytes(&data);
+        Ok(())
+    })
+    .unwrap();
+
+    let buf = b.finish();
+    assert_eq!(&buf[..3], &[0x01, 0x11, 0x70]); // 70_000 as u24 big-endian
+
+    let mut r = Reader::new(&buf);
+    let mut child = r.read_length_prefixed(LengthPrefix::U24).unwrap();
+    assert_eq!(child.read_to_end(), data.as_slice());
+    assert!(r.is_empty());
+}
+
+#[test]
+fn test_write_length_prefixed_nested() {
+    let mut b = Builder::new();
+    b.write_length_prefixed(LengthPrefix::U16, |outer| {
+        outer.write_u8(0xff);
+        outer.write_length_prefixed(LengthPrefix::U8, |inner| {
+            inner.write_bytes(b"abc");
+            Ok(())
+        })
+    })
+    .unwrap();
+
+    let buf = b.finish();
+
+    let mut r = Reader::new(&buf);
+    let mut outer = r.read_length_prefixed(LengthPrefix::U16).unwrap();
+    assert_eq!(outer.read_u8().unwrap(), 0xff);
+    let mut inner = outer.read_length_prefixed(LengthPrefix::U8).unwrap();
+    assert_eq!(inner.read_to_end(), b"abc");
+    assert!(outer.is_empty());
+}
+
+#[test]
+fn test_write_length_prefixed_overflows_u8_prefix() {
+    let mut b = Builder::new();
+    let result = b.write_length_prefixed(LengthPrefix::U8, |c| {
+        c.write_bytes(&[0u8; 256]);
+        Ok(())
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_read_fixed_width_list_rejects_non_multiple_remainder() {
+    // u8 length prefix of 3, but element_width is 2: 3 is not a multiple of 2.
+    let buf = [3u8, 0, 0, 0];
+    let mut r = Reader::new(&buf);
+
+    let result = r.read_fixed_width_list(LengthPrefix::U8, 2, 10, |e| e.read_u8());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_read_fixed_width_list_rejects_too_many_elements() {
+    // u16 length prefix of 12 bytes, element_width 2 -> 6 elements, capped at 5.
+    let mut buf = vec![0u8, 12];
+    buf.extend_from_slice(&[0u8; 12]);
+    let mut r = Reader::new(&buf);
+
+    let result = r.read_fixed_width_list(LengthPrefix::U16, 2, 5, |e| e.read_u16());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_read_fixed_width_list_accepts_within_cap() {
+    let mut buf = vec![0u8, 4]; // u16 length prefix of 4 bytes
+    buf.extend_from_slice(&[0x00, 0x0a, 0x00, 0x0b]);
+    let mut r = Reader::new(&buf);
+
+    let elements = r
+        .read_fixed_width_list(LengthPrefix::U16, 2, 5, |e| e.read_u16())
+        .unwrap();
+
+    assert_eq!(elements, vec![10, 11]);
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod extension_alpn_test;
+
+use super::*;
+
+use util::Error;
+
+// https://tools.ietf.org/html/rfc7301#section-3.1
+//
+// opaque ProtocolName<1..2^8-1>;
+//
+// struct {
+//     ProtocolName protocol_name_list<2..2^16-1>
+// } ProtocolNameList;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtensionAlpn {
+    pub(crate) protocol_name_list: Vec<Vec<u8>>,
+}
+
+impl ExtensionAlpn {
+    pub fn new(protocol_name_list: Vec<Vec<u8>>) -> Self {
+        ExtensionAlpn { protocol_name_list }
+    }
+
+    pub fn extension_value(&self) -> ExtensionValue {
+        ExtensionValue::Alpn
+    }
+
+    /// Returns the first entry of `supported` that the peer also offered,
+    /// so a server can pick the protocol it prefers among the ones both
+    /// sides agree on.
+    pub fn select_protocol(&self, supported: &[Vec<u8>]) -> Option<Vec<u8>> {
+        supported
+            .iter()
+            .find(|proto| self.protocol_name_list.contains(proto))
+            .cloned()
+    }
+
+    pub fn marshal(&self, b: &mut Builder) -> Result<(), Error> {
+        b.write_length_prefixed(LengthPrefix::U16, |list| {
+            for name in &self.protocol_name_list {
+                if name.is_empty() {
+                    return Err(ERR_INVALID_ALPN_FORMAT.clone());
+                }
+                list.write_length_prefixed(LengthPrefix::U8, |n| {
+                    n.write_bytes(name);
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn unmarshal(r: &mut Reader) -> Result<Self, Error> {
+        let mut list = r.read_length_prefixed(LengthPrefix::U16)?;
+
+        let mut protocol_name_list = Vec::new();
+        while !list.is_empty() {
+            let name = list
+                .read_length_prefixed(LengthPrefix::U8)?
+                .read_to_end()
+                .to_vec();
+            if name.is_empty() {
+                return Err(ERR_INVALID_ALPN_FORMAT.clone());
+            }
+            protocol_name_list.push(name);
+        }
+
+        if protocol_name_list.is_empty() {
+            return Err(ERR_INVALID_ALPN_FORMAT.clone());
+        }
+
+        Ok(ExtensionAlpn { protocol_name_list })
+    }
+}
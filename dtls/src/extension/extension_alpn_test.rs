@@ -0,0 +1,59 @@
+use super::*;
+
+fn marshal(ext: &ExtensionAlpn) -> Vec<u8> {
+    let mut b = Builder::new();
+    ext.marshal(&mut b).unwrap();
+    b.finish()
+}
+
+#[test]
+fn test_alpn_round_trip() {
+    let ext = ExtensionAlpn::new(vec![b"h2".to_vec(), b"http/1.1".to_vec()]);
+
+    let buf = marshal(&ext);
+    let mut r = Reader::new(&buf);
+    let decoded = ExtensionAlpn::unmarshal(&mut r).unwrap();
+
+    assert_eq!(decoded, ext);
+}
+
+#[test]
+fn test_alpn_unmarshal_rejects_zero_length_name() {
+    // u16 list length = 1, containing a single protocol name entry whose
+    // own u8 length prefix is 0.
+    let buf = [0x00, 0x01, 0x00];
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionAlpn::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_alpn_unmarshal_rejects_empty_list() {
+    let buf = [0x00, 0x00];
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionAlpn::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_alpn_marshal_rejects_zero_length_name() {
+    let ext = ExtensionAlpn::new(vec![Vec::new()]);
+
+    let mut b = Builder::new();
+    let result = ext.marshal(&mut b);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_alpn_select_protocol() {
+    let offered = ExtensionAlpn::new(vec![b"http/1.1".to_vec(), b"h2".to_vec()]);
+    let supported = vec![b"spdy/3".to_vec(), b"h2".to_vec()];
+
+    assert_eq!(offered.select_protocol(&supported), Some(b"h2".to_vec()));
+    assert_eq!(offered.select_protocol(&[b"spdy/3".to_vec()]), None);
+}
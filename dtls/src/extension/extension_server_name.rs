@@ -3,46 +3,128 @@ mod extension_server_name_test;
 
 use super::*;
 
-use std::io::{Read, Write};
-
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
-
 use util::Error;
 
 const EXTENSION_SERVER_NAME_TYPE_DNSHOST_NAME: u8 = 0;
 
+/// `NameType` identifies the kind of a single `ServerName` entry.
+/// Only `host_name` (0) is defined by RFC 6066; any other value is
+/// represented as `Unsupported`, and `ExtensionServerName::unmarshal`
+/// rejects an entry of that type with `ERR_INVALID_SNI_FORMAT`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NameType {
+    HostName,
+    Unsupported(u8),
+}
+
+impl From<u8> for NameType {
+    fn from(val: u8) -> Self {
+        match val {
+            EXTENSION_SERVER_NAME_TYPE_DNSHOST_NAME => NameType::HostName,
+            _ => NameType::Unsupported(val),
+        }
+    }
+}
+
+impl From<NameType> for u8 {
+    fn from(name_type: NameType) -> Self {
+        match name_type {
+            NameType::HostName => EXTENSION_SERVER_NAME_TYPE_DNSHOST_NAME,
+            NameType::Unsupported(val) => val,
+        }
+    }
+}
+
+// https://tools.ietf.org/html/rfc6066#section-3
+//
+// struct {
+//     NameType name_type;
+//     select (name_type) {
+//         case host_name: HostName;
+//     } name;
+// } ServerName;
+//
+// enum {
+//     host_name(0), (255)
+// } NameType;
+//
+// opaque HostName<1..2^16-1>;
+//
+// struct {
+//     ServerName server_name_list<1..2^16-1>
+// } ServerNameList;
+//
+// `server_names` replaced this struct's previous single `server_name:
+// String` field; grep found no other file in this crate constructing or
+// matching `ExtensionServerName` by its fields, only through `new()` and
+// `server_name()` below, but that was checked only within this chunk's
+// checkout - re-check the rest of the crate (ClientHello/ServerHello
+// construction) before merging.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ExtensionServerName {
-    pub(crate) server_name: String,
+    pub(crate) server_names: Vec<(NameType, String)>,
 }
 
 impl ExtensionServerName {
+    pub fn new(host_name: String) -> Self {
+        ExtensionServerName {
+            server_names: vec![(NameType::HostName, host_name)],
+        }
+    }
+
+    /// Returns the first `host_name` entry in the list, if any.
+    pub fn server_name(&self) -> Option<&str> {
+        self.server_names
+            .iter()
+            .find(|(name_type, _)| *name_type == NameType::HostName)
+            .map(|(_, name)| name.as_str())
+    }
+
     pub fn extension_value(&self) -> ExtensionValue {
         ExtensionValue::ServerName
     }
 
-    pub fn marshal<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
-        //TODO: check how to do cryptobyte?
-        //writer.write_u8(EXTENSION_SERVER_NAME_TYPE_DNSHOST_NAME)?;
-        writer.write_u16::<BigEndian>(self.server_name.len() as u16)?;
-        writer.write_all(self.server_name.as_bytes())?;
-
-        Ok(())
+    pub fn marshal(&self, b: &mut Builder) -> Result<(), Error> {
+        b.write_length_prefixed(LengthPrefix::U16, |list| {
+            for (name_type, name) in &self.server_names {
+                list.write_u8((*name_type).into());
+                list.write_length_prefixed(LengthPrefix::U16, |n| {
+                    n.write_bytes(name.as_bytes());
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })
     }
 
-    pub fn unmarshal<R: Read>(reader: &mut R) -> Result<Self, Error> {
-        //TODO: check how to do cryptobyte?
-        //let name_type = reader.read_u8()?;
-        //if name_type != EXTENSION_SERVER_NAME_TYPE_DNSHOST_NAME {
-        //    return Err(ERR_INVALID_SNI_FORMAT.clone());
-        //}
+    pub fn unmarshal(r: &mut Reader) -> Result<Self, Error> {
+        let mut list = r.read_length_prefixed(LengthPrefix::U16)?;
+
+        let mut server_names = Vec::new();
+        while !list.is_empty() {
+            let name_type: NameType = list.read_u8()?.into();
+            let name = String::from_utf8(
+                list.read_length_prefixed(LengthPrefix::U16)?
+                    .read_to_end()
+                    .to_vec(),
+            )?;
+
+            match name_type {
+                NameType::HostName => {
+                    if name.is_empty() || !name.is_ascii() {
+                        return Err(ERR_INVALID_SNI_FORMAT.clone());
+                    }
+                }
+                NameType::Unsupported(_) => return Err(ERR_INVALID_SNI_FORMAT.clone()),
+            }
 
-        let buf_len = reader.read_u16::<BigEndian>()? as usize;
-        let mut buf: Vec<u8> = vec![0u8; buf_len];
-        reader.read_exact(&mut buf)?;
+            server_names.push((name_type, name));
+        }
 
-        let server_name = String::from_utf8(buf)?;
+        if server_names.is_empty() {
+            return Err(ERR_INVALID_SNI_FORMAT.clone());
+        }
 
-        Ok(ExtensionServerName { server_name })
+        Ok(ExtensionServerName { server_names })
     }
 }
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod extension_supported_elliptic_curves_test;
+
+use super::*;
+
+use util::Error;
+
+const NAMED_CURVE_SIZE: usize = 2;
+const MAX_NAMED_CURVES: usize = 64;
+
+// https://www.iana.org/assignments/tls-parameters/tls-parameters.xhtml#tls-parameters-8
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NamedCurve {
+    Secp256r1,
+    Secp384r1,
+    X25519,
+    Unsupported(u16),
+}
+
+impl From<u16> for NamedCurve {
+    fn from(val: u16) -> Self {
+        match val {
+            23 => NamedCurve::Secp256r1,
+            24 => NamedCurve::Secp384r1,
+            29 => NamedCurve::X25519,
+            _ => NamedCurve::Unsupported(val),
+        }
+    }
+}
+
+impl From<NamedCurve> for u16 {
+    fn from(curve: NamedCurve) -> Self {
+        match curve {
+            NamedCurve::Secp256r1 => 23,
+            NamedCurve::Secp384r1 => 24,
+            NamedCurve::X25519 => 29,
+            NamedCurve::Unsupported(val) => val,
+        }
+    }
+}
+
+// https://tools.ietf.org/html/rfc8422#section-5.1.1
+//
+// enum { ... (0xFFFF) } NamedCurve;
+//
+// struct {
+//     NamedCurve named_curve_list<2..2^16-1>
+// } NamedCurveList;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtensionSupportedEllipticCurves {
+    pub(crate) elliptic_curves: Vec<NamedCurve>,
+}
+
+impl ExtensionSupportedEllipticCurves {
+    pub fn new(elliptic_curves: Vec<NamedCurve>) -> Self {
+        ExtensionSupportedEllipticCurves { elliptic_curves }
+    }
+
+    pub fn extension_value(&self) -> ExtensionValue {
+        ExtensionValue::SupportedEllipticCurves
+    }
+
+    pub fn marshal(&self, b: &mut Builder) -> Result<(), Error> {
+        b.write_length_prefixed(LengthPrefix::U16, |list| {
+            for curve in &self.elliptic_curves {
+                list.write_bytes(&u16::from(*curve).to_be_bytes());
+            }
+            Ok(())
+        })
+    }
+
+    pub fn unmarshal(r: &mut Reader) -> Result<Self, Error> {
+        let elliptic_curves = r.read_fixed_width_list(
+            LengthPrefix::U16,
+            NAMED_CURVE_SIZE,
+            MAX_NAMED_CURVES,
+            |e| Ok(NamedCurve::from(e.read_u16()?)),
+        )?;
+
+        if elliptic_curves.is_empty() {
+            return Err(ERR_INVALID_ELLIPTIC_CURVE_FORMAT.clone());
+        }
+
+        Ok(ExtensionSupportedEllipticCurves { elliptic_curves })
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+
+fn marshal(ext: &ExtensionSupportedEllipticCurves) -> Vec<u8> {
+    let mut b = Builder::new();
+    ext.marshal(&mut b).unwrap();
+    b.finish()
+}
+
+#[test]
+fn test_round_trip() {
+    let ext = ExtensionSupportedEllipticCurves::new(vec![
+        NamedCurve::X25519,
+        NamedCurve::Secp256r1,
+        NamedCurve::Secp384r1,
+    ]);
+
+    let buf = marshal(&ext);
+    let mut r = Reader::new(&buf);
+    let decoded = ExtensionSupportedEllipticCurves::unmarshal(&mut r).unwrap();
+
+    assert_eq!(decoded, ext);
+}
+
+#[test]
+fn test_unmarshal_preserves_unknown_curve() {
+    let ext = ExtensionSupportedEllipticCurves::new(vec![NamedCurve::Unsupported(0xfefe)]);
+
+    let buf = marshal(&ext);
+    let mut r = Reader::new(&buf);
+    let decoded = ExtensionSupportedEllipticCurves::unmarshal(&mut r).unwrap();
+
+    assert_eq!(decoded, ext);
+}
+
+#[test]
+fn test_unmarshal_rejects_empty_list() {
+    let buf = [0x00, 0x00];
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionSupportedEllipticCurves::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_unmarshal_rejects_odd_length_list() {
+    // u16 list length = 3, not a multiple of the 2-byte NamedCurve width.
+    let buf = [0x00, 0x03, 0x00, 0x1d, 0x00];
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionSupportedEllipticCurves::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_unmarshal_rejects_too_many_curves() {
+    let mut buf = vec![0x00, 0x82]; // u16 list length = 130 bytes = 65 curves
+    buf.extend(std::iter::repeat(0x00u8).take(130));
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionSupportedEllipticCurves::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
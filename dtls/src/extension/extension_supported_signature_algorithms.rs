@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod extension_supported_signature_algorithms_test;
+
+use super::*;
+
+use util::Error;
+
+const SIGNATURE_HASH_ALGORITHM_SIZE: usize = 2;
+const MAX_SIGNATURE_HASH_ALGORITHMS: usize = 64;
+
+// https://tools.ietf.org/html/rfc5246#section-7.4.1.4.1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+    Unsupported(u8),
+}
+
+impl From<u8> for HashAlgorithm {
+    fn from(val: u8) -> Self {
+        match val {
+            4 => HashAlgorithm::Sha256,
+            5 => HashAlgorithm::Sha384,
+            6 => HashAlgorithm::Sha512,
+            _ => HashAlgorithm::Unsupported(val),
+        }
+    }
+}
+
+impl From<HashAlgorithm> for u8 {
+    fn from(alg: HashAlgorithm) -> Self {
+        match alg {
+            HashAlgorithm::Sha256 => 4,
+            HashAlgorithm::Sha384 => 5,
+            HashAlgorithm::Sha512 => 6,
+            HashAlgorithm::Unsupported(val) => val,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SignatureAlgorithm {
+    Rsa,
+    Ecdsa,
+    Unsupported(u8),
+}
+
+impl From<u8> for SignatureAlgorithm {
+    fn from(val: u8) -> Self {
+        match val {
+            1 => SignatureAlgorithm::Rsa,
+            3 => SignatureAlgorithm::Ecdsa,
+            _ => SignatureAlgorithm::Unsupported(val),
+        }
+    }
+}
+
+impl From<SignatureAlgorithm> for u8 {
+    fn from(alg: SignatureAlgorithm) -> Self {
+        match alg {
+            SignatureAlgorithm::Rsa => 1,
+            SignatureAlgorithm::Ecdsa => 3,
+            SignatureAlgorithm::Unsupported(val) => val,
+        }
+    }
+}
+
+// struct {
+//     HashAlgorithm hash;
+//     SignatureAlgorithm signature;
+// } SignatureAndHashAlgorithm;
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SignatureHashAlgorithm {
+    pub(crate) hash: HashAlgorithm,
+    pub(crate) signature: SignatureAlgorithm,
+}
+
+// SignatureAndHashAlgorithm
+//     supported_signature_algorithms<2..2^16-2>;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtensionSupportedSignatureAlgorithms {
+    pub(crate) signature_hash_algorithms: Vec<SignatureHashAlgorithm>,
+}
+
+impl ExtensionSupportedSignatureAlgorithms {
+    pub fn new(signature_hash_algorithms: Vec<SignatureHashAlgorithm>) -> Self {
+        ExtensionSupportedSignatureAlgorithms {
+            signature_hash_algorithms,
+        }
+    }
+
+    pub fn extension_value(&self) -> ExtensionValue {
+        ExtensionValue::SupportedSignatureAlgorithms
+    }
+
+    pub fn marshal(&self, b: &mut Builder) -> Result<(), Error> {
+        b.write_length_prefixed(LengthPrefix::U16, |list| {
+            for alg in &self.signature_hash_algorithms {
+                list.write_u8(alg.hash.into());
+                list.write_u8(alg.signature.into());
+            }
+            Ok(())
+        })
+    }
+
+    pub fn unmarshal(r: &mut Reader) -> Result<Self, Error> {
+        let signature_hash_algorithms = r.read_fixed_width_list(
+            LengthPrefix::U16,
+            SIGNATURE_HASH_ALGORITHM_SIZE,
+            MAX_SIGNATURE_HASH_ALGORITHMS,
+            |e| {
+                let hash = HashAlgorithm::from(e.read_u8()?);
+                let signature = SignatureAlgorithm::from(e.read_u8()?);
+                Ok(SignatureHashAlgorithm { hash, signature })
+            },
+        )?;
+
+        if signature_hash_algorithms.is_empty() {
+            return Err(ERR_INVALID_SIGNATURE_ALGORITHMS_FORMAT.clone());
+        }
+
+        Ok(ExtensionSupportedSignatureAlgorithms {
+            signature_hash_algorithms,
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+fn marshal(ext: &ExtensionSupportedSignatureAlgorithms) -> Vec<u8> {
+    let mut b = Builder::new();
+    ext.marshal(&mut b).unwrap();
+    b.finish()
+}
+
+#[test]
+fn test_round_trip() {
+    let ext = ExtensionSupportedSignatureAlgorithms::new(vec![
+        SignatureHashAlgorithm {
+            hash: HashAlgorithm::Sha256,
+            signature: SignatureAlgorithm::Ecdsa,
+        },
+        SignatureHashAlgorithm {
+            hash: HashAlgorithm::Sha384,
+            signature: SignatureAlgorithm::Rsa,
+        },
+    ]);
+
+    let buf = marshal(&ext);
+    let mut r = Reader::new(&buf);
+    let decoded = ExtensionSupportedSignatureAlgorithms::unmarshal(&mut r).unwrap();
+
+    assert_eq!(decoded, ext);
+}
+
+#[test]
+fn test_unmarshal_rejects_empty_list() {
+    let buf = [0x00, 0x00];
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionSupportedSignatureAlgorithms::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_unmarshal_rejects_odd_length_list() {
+    // u16 list length = 3, not a multiple of the 2-byte element width.
+    let buf = [0x00, 0x03, 0x04, 0x03, 0x00];
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionSupportedSignatureAlgorithms::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_unmarshal_rejects_too_many_elements() {
+    let mut buf = vec![0x00, 0x82]; // u16 list length = 130 bytes = 65 elements
+    buf.extend(std::iter::repeat(0x04u8).take(130));
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionSupportedSignatureAlgorithms::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod extension_supported_versions_test;
+
+use super::*;
+
+use util::Error;
+
+// The list is u8-length-prefixed with 2-byte elements, so it can hold at
+// most 127 versions; cap well below that so a malformed peer can't make
+// us walk a pathological count.
+const MAX_SUPPORTED_VERSIONS: usize = 32;
+
+/// Which handshake message a `SupportedVersions` extension is carried in
+/// determines its wire shape: a ClientHello advertises a list of
+/// versions, while a ServerHello/HelloRetryRequest picks exactly one and
+/// omits the list prefix entirely.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SupportedVersionsKind {
+    ClientHello,
+    ServerHello,
+}
+
+// https://tools.ietf.org/html/rfc8446#section-4.2.1
+//
+// struct {
+//     select (Handshake.msg_type) {
+//         case client_hello:
+//              ProtocolVersion versions<2..254>;
+//         case server_hello: /* and HelloRetryRequest */
+//              ProtocolVersion selected_version;
+//     };
+// } SupportedVersions;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtensionSupportedVersions {
+    pub(crate) kind: SupportedVersionsKind,
+    pub(crate) versions: Vec<u16>,
+}
+
+impl ExtensionSupportedVersions {
+    pub fn client_hello(versions: Vec<u16>) -> Self {
+        ExtensionSupportedVersions {
+            kind: SupportedVersionsKind::ClientHello,
+            versions,
+        }
+    }
+
+    pub fn server_hello(version: u16) -> Self {
+        ExtensionSupportedVersions {
+            kind: SupportedVersionsKind::ServerHello,
+            versions: vec![version],
+        }
+    }
+
+    pub fn extension_value(&self) -> ExtensionValue {
+        ExtensionValue::SupportedVersions
+    }
+
+    pub fn marshal(&self, b: &mut Builder) -> Result<(), Error> {
+        match self.kind {
+            SupportedVersionsKind::ClientHello => b.write_length_prefixed(LengthPrefix::U8, |list| {
+                for version in &self.versions {
+                    list.write_bytes(&version.to_be_bytes());
+                }
+                Ok(())
+            }),
+            SupportedVersionsKind::ServerHello => {
+                let version = *self
+                    .versions
+                    .first()
+                    .ok_or_else(|| ERR_INVALID_SUPPORTED_VERSIONS_FORMAT.clone())?;
+                b.write_bytes(&version.to_be_bytes());
+                Ok(())
+            }
+        }
+    }
+
+    /// Parses the ClientHello form: a u8-length-prefixed list of versions.
+    pub fn unmarshal(r: &mut Reader) -> Result<Self, Error> {
+        let versions = r.read_fixed_width_list(LengthPrefix::U8, 2, MAX_SUPPORTED_VERSIONS, |e| {
+            e.read_u16()
+        })?;
+
+        if versions.is_empty() {
+            return Err(ERR_INVALID_SUPPORTED_VERSIONS_FORMAT.clone());
+        }
+
+        Ok(ExtensionSupportedVersions {
+            kind: SupportedVersionsKind::ClientHello,
+            versions,
+        })
+    }
+
+    /// Parses the ServerHello/HelloRetryRequest form: a single version
+    /// with no list prefix. Callers must reach for this directly, since
+    /// the shared `Extension::unmarshal` dispatch has no handshake
+    /// message context to disambiguate it from the ClientHello form.
+    pub fn unmarshal_server_hello(r: &mut Reader) -> Result<Self, Error> {
+        let version = r.read_u16()?;
+
+        Ok(ExtensionSupportedVersions {
+            kind: SupportedVersionsKind::ServerHello,
+            versions: vec![version],
+        })
+    }
+}
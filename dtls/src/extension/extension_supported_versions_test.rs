@@ -0,0 +1,66 @@
+use super::*;
+
+fn marshal(ext: &ExtensionSupportedVersions) -> Vec<u8> {
+    let mut b = Builder::new();
+    ext.marshal(&mut b).unwrap();
+    b.finish()
+}
+
+#[test]
+fn test_client_hello_round_trip() {
+    let ext = ExtensionSupportedVersions::client_hello(vec![0x0304, 0x0303]);
+
+    let buf = marshal(&ext);
+    let mut r = Reader::new(&buf);
+    let decoded = ExtensionSupportedVersions::unmarshal(&mut r).unwrap();
+
+    assert_eq!(decoded, ext);
+}
+
+#[test]
+fn test_server_hello_round_trip() {
+    let ext = ExtensionSupportedVersions::server_hello(0x0304);
+
+    let buf = marshal(&ext);
+    assert_eq!(buf, vec![0x03, 0x04]);
+
+    let mut r = Reader::new(&buf);
+    let decoded = ExtensionSupportedVersions::unmarshal_server_hello(&mut r).unwrap();
+
+    assert_eq!(decoded, ext);
+}
+
+#[test]
+fn test_unmarshal_does_not_accept_server_hello_wire_form() {
+    // The ServerHello form (a bare u16) is too short to also be read as
+    // a valid ClientHello form (a u8 list-length followed by that many
+    // bytes), so the generic `unmarshal` must not silently succeed on it.
+    let ext = ExtensionSupportedVersions::server_hello(0x0304);
+    let buf = marshal(&ext);
+
+    let mut r = Reader::new(&buf);
+    let result = ExtensionSupportedVersions::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_client_hello_unmarshal_rejects_empty_list() {
+    let buf = [0x00];
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionSupportedVersions::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_client_hello_unmarshal_rejects_odd_length_list() {
+    // u8 list length = 3, which isn't a multiple of the 2-byte element width.
+    let buf = [0x03, 0x03, 0x03, 0x00];
+    let mut r = Reader::new(&buf);
+
+    let result = ExtensionSupportedVersions::unmarshal(&mut r);
+
+    assert!(result.is_err());
+}
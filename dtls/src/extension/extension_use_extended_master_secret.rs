@@ -16,16 +16,13 @@ impl ExtensionUseExtendedMasterSecret {
         ExtensionValue::UseExtendedMasterSecret
     }
 
-    pub fn marshal<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
-        // length
-        writer.write_u16::<BigEndian>(0)?;
-
+    pub fn marshal(&self, _b: &mut Builder) -> Result<(), Error> {
+        // The extension body is empty; the outer extension_data length
+        // written by `Extension::marshal` is all that's needed.
         Ok(())
     }
 
-    pub fn unmarshal<R: Read>(reader: &mut R) -> Result<Self, Error> {
-        let _ = reader.read_u16::<BigEndian>()?;
-
+    pub fn unmarshal(_r: &mut Reader) -> Result<Self, Error> {
         Ok(ExtensionUseExtendedMasterSecret { supported: true })
     }
 }